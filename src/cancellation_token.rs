@@ -0,0 +1,134 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use event_listener::{Event, EventListener};
+
+struct Inner {
+    cancelled: AtomicBool,
+    event: Event,
+    children: Mutex<Vec<Arc<Inner>>>,
+}
+
+impl Inner {
+    fn new() -> Arc<Self> {
+        Arc::new(Inner {
+            cancelled: AtomicBool::new(false),
+            event: Event::new(),
+            children: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn cancel(self: &Arc<Self>) {
+        if self.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        // Take the children under the lock before notifying, mirroring the register-after-notify
+        // discipline `Sender::disconnect_notice` relies on: a `child_token` call racing with this
+        // will either observe `cancelled` already set (and cancel itself immediately) or will
+        // still be in `children` when we read it here, never neither.
+        let children = self.children.lock().unwrap().clone();
+
+        self.event.notify(usize::MAX);
+
+        for child in children {
+            child.cancel();
+        }
+    }
+}
+
+/// A token for cooperative cancellation of in-flight actor work, modeled on tokio's
+/// `CancellationToken`.
+///
+/// Tokens form a tree: cancelling a token cancels all of its descendants, but a child's
+/// cancellation has no effect on its parent or siblings. This lets a [`Handler`](crate::Handler)
+/// hold a child token to abort its own long-running async work when asked to stop, which
+/// [`Sender::stop_all_receivers`](crate::inbox::tx::Sender::stop_all_receivers) alone can't
+/// express on its own.
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+impl CancellationToken {
+    /// Creates a new, independent cancellation token with no parent.
+    pub fn new() -> Self {
+        CancellationToken { inner: Inner::new() }
+    }
+
+    /// Derives a child token. Cancelling `self`, or any of its ancestors, cancels the child, but
+    /// cancelling the child has no effect on `self`.
+    pub fn child_token(&self) -> CancellationToken {
+        let child = Inner::new();
+
+        // Same register-after-notify race as `cancel` above: lock `children` before checking
+        // `cancelled`, so a concurrent `cancel` can't be missed by both paths.
+        let mut children = self.inner.children.lock().unwrap();
+        if self.inner.cancelled.load(Ordering::SeqCst) {
+            child.cancel();
+        } else {
+            children.push(child.clone());
+        }
+
+        CancellationToken { inner: child }
+    }
+
+    /// Cancels this token and all of its descendants.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns `true` if this token, or one of its ancestors, has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns a future that resolves once this token is cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            listener: None,
+        }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        CancellationToken::new()
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`].
+#[must_use = "Futures do nothing unless polled"]
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    listener: Option<EventListener>,
+}
+
+impl<'a> Future for Cancelled<'a> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            if self.token.is_cancelled() {
+                return Poll::Ready(());
+            }
+
+            match self.listener.take() {
+                Some(mut listener) => {
+                    if Pin::new(&mut listener).poll(cx).is_pending() {
+                        self.listener = Some(listener);
+                        return Poll::Pending;
+                    }
+                    // Spuriously woken before `cancel` set the flag above; re-check on the next
+                    // loop iteration instead of reporting a false positive.
+                }
+                None => self.listener = Some(self.token.inner.event.listen()),
+            }
+        }
+    }
+}
+
@@ -0,0 +1,190 @@
+//! The guts of a bounded (or unbounded) actor mailbox: the shared [`Chan`] state plus the
+//! [`tx`] and [`rx`] halves built on top of it.
+
+pub mod rx;
+pub mod tx;
+
+use std::collections::{BinaryHeap, VecDeque};
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use event_listener::Event;
+
+use crate::cancellation_token::CancellationToken;
+use crate::envelope::{BroadcastEnvelope, MessageEnvelope};
+use crate::inbox::rx::WaitingReceiver;
+use crate::inbox::tx::{Semaphore, WaitingSender};
+
+/// A short critical section around `WaitingSender`/`WaitingReceiver` state. Despite the name, this
+/// is a thin wrapper around `std::sync::Mutex`, not a spinning lock.
+pub(crate) struct Spinlock<T>(Mutex<T>);
+
+impl<T> Spinlock<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Spinlock(Mutex::new(value))
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+        self.0.lock().unwrap()
+    }
+}
+
+/// A message in transit, either addressed to a single actor or broadcast to every actor on the
+/// channel.
+///
+/// `pub` rather than `pub(crate)` because it appears in the signature of
+/// [`Sender::send`](tx::Sender::send) and [`SendFutureInner`](tx::SendFutureInner::New).
+pub enum SentMessage<A> {
+    ToOneActor(MessageToOneActor<A>),
+    ToAllActors(Arc<dyn BroadcastEnvelope<Actor = A>>),
+}
+
+/// A message bound for a single actor, together with the priority it was sent with.
+pub struct MessageToOneActor<A> {
+    pub val: Box<dyn MessageEnvelope<Actor = A>>,
+    pub priority: u32,
+}
+
+impl<A> PartialEq for MessageToOneActor<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<A> Eq for MessageToOneActor<A> {}
+
+impl<A> PartialOrd for MessageToOneActor<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A> Ord for MessageToOneActor<A> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// A message broadcast to every actor on the channel.
+pub struct MessageToAllActors<A>(pub Arc<dyn BroadcastEnvelope<Actor = A>>);
+
+/// The reason a parked [`WaitingReceiver`] is being woken.
+pub enum WakeReason<A> {
+    MessageToOneActor(MessageToOneActor<A>),
+    Shutdown,
+}
+
+/// The ways a [`Sender`](tx::Sender) can fail to hand a message off immediately.
+pub(crate) enum TrySendFail<A> {
+    /// There are no receivers left to deliver to.
+    Disconnected,
+    /// The channel is at capacity; `message` was instead parked as a waiting sender, which will
+    /// be woken once room frees up.
+    Full(Arc<Spinlock<WaitingSender<A>>>),
+}
+
+struct ChanInner<A> {
+    ordered_queue: VecDeque<Box<dyn MessageEnvelope<Actor = A>>>,
+    priority_queue: BinaryHeap<MessageToOneActor<A>>,
+    /// Count of broadcasts ever sent on this channel. Each `Receiver` tracks its own read
+    /// position into this count independently; the messages themselves live in per-receiver
+    /// broadcast mailboxes rather than here.
+    broadcast_tail: usize,
+    waiting_senders: VecDeque<Weak<Spinlock<WaitingSender<A>>>>,
+    waiting_receivers: VecDeque<Weak<Spinlock<WaitingReceiver<A>>>>,
+}
+
+impl<A> ChanInner<A> {
+    /// Hands `reason` directly to the first live parked receiver, without ever touching a queue.
+    /// Returns the reason back if there was no receiver currently parked to take it.
+    fn try_fulfill_receiver(&mut self, reason: WakeReason<A>) -> Result<(), WakeReason<A>> {
+        while let Some(weak) = self.waiting_receivers.pop_front() {
+            if let Some(waiting) = weak.upgrade() {
+                return waiting.lock().fulfill(reason);
+            }
+        }
+
+        Err(reason)
+    }
+
+    fn send_broadcast(&mut self, _message: MessageToAllActors<A>) {
+        self.broadcast_tail += 1;
+    }
+}
+
+/// The state shared between every [`Sender`](tx::Sender) and `Receiver` cloned from the same
+/// mailbox.
+pub struct Chan<A> {
+    chan: Mutex<ChanInner<A>>,
+    capacity: Option<usize>,
+    semaphore: Semaphore,
+    receiver_count: AtomicUsize,
+    sender_count: AtomicUsize,
+    on_shutdown: Event,
+    /// The root of this channel's [`CancellationToken`] tree. Cancelled exactly when
+    /// `on_shutdown` is notified (see [`Chan::shutdown`]), so the two always fire together.
+    shutdown_token: CancellationToken,
+}
+
+impl<A> Chan<A> {
+    pub(crate) fn new(capacity: Option<usize>) -> Arc<Self> {
+        Arc::new(Chan {
+            chan: Mutex::new(ChanInner {
+                ordered_queue: VecDeque::new(),
+                priority_queue: BinaryHeap::new(),
+                broadcast_tail: 0,
+                waiting_senders: VecDeque::new(),
+                waiting_receivers: VecDeque::new(),
+            }),
+            capacity,
+            semaphore: Semaphore::new(capacity),
+            receiver_count: AtomicUsize::new(0),
+            sender_count: AtomicUsize::new(0),
+            on_shutdown: Event::new(),
+            shutdown_token: CancellationToken::new(),
+        })
+    }
+
+    fn is_full(&self, len: usize) -> bool {
+        self.capacity.map_or(false, |cap| len >= cap)
+    }
+
+    /// Notifies every `on_shutdown` listener and cancels the channel's root
+    /// [`CancellationToken`] in one step, so the two can never drift out of sync. Called once
+    /// the last `Receiver` for this channel is dropped.
+    pub(crate) fn shutdown(&self) {
+        self.on_shutdown.notify(usize::MAX);
+        self.shutdown_token.cancel();
+    }
+
+    /// Releases one permit of send capacity and, if a [`Sender`](tx::Sender) is currently parked
+    /// waiting for room, wakes the first live one so it re-attempts its send. Called both when a
+    /// message is dequeued on the receive side (freeing the permit it was holding) and when a
+    /// reservation is dropped unused, so both paths wake parked senders the same way.
+    ///
+    /// Takes the `chan` lock itself, so callers must not already hold it — see
+    /// [`Chan::release_send_permit_locked`] for that case.
+    pub(crate) fn release_send_permit(&self) {
+        self.semaphore.release();
+        Self::wake_parked_sender(&self.chan.lock().unwrap());
+    }
+
+    /// Same as [`Chan::release_send_permit`], but for callers that are already holding the
+    /// `chan` guard (e.g. mid-`match` on [`ChanInner::try_fulfill_receiver`]'s result). Re-taking
+    /// `chan`'s lock here instead would deadlock, since `std::sync::Mutex` isn't reentrant.
+    pub(crate) fn release_send_permit_locked(&self, inner: &ChanInner<A>) {
+        self.semaphore.release();
+        Self::wake_parked_sender(inner);
+    }
+
+    fn wake_parked_sender(inner: &ChanInner<A>) {
+        for weak in &inner.waiting_senders {
+            if let Some(waiting) = weak.upgrade() {
+                if let Some(waker) = waiting.lock().take_waker() {
+                    waker.wake();
+                    break;
+                }
+            }
+        }
+    }
+}
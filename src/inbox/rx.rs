@@ -0,0 +1,156 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+use crate::envelope::MessageEnvelope;
+use crate::inbox::{Chan, MessageToOneActor, Spinlock, WakeReason};
+
+/// The receiving half of a mailbox, cloned once per running actor instance.
+pub(crate) struct Receiver<A> {
+    inner: Arc<Chan<A>>,
+    waiting: Option<Arc<Spinlock<WaitingReceiver<A>>>>,
+}
+
+impl<A> Receiver<A> {
+    pub(crate) fn new(inner: Arc<Chan<A>>) -> Self {
+        inner.receiver_count.fetch_add(1, Ordering::SeqCst);
+
+        Receiver {
+            inner,
+            waiting: None,
+        }
+    }
+
+    /// Pops the next message for this actor, parking until one arrives if the mailbox is
+    /// currently empty.
+    pub(crate) fn recv(&mut self) -> ReceiveFuture<'_, A> {
+        ReceiveFuture { receiver: self }
+    }
+
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<Box<dyn MessageEnvelope<Actor = A>>>> {
+        if let Some(waiting) = self.waiting.take() {
+            let mut inner = waiting.lock();
+            match &mut inner.state {
+                WaitingReceiverInner::Active => {
+                    inner.waker = Some(cx.waker().clone());
+                    drop(inner);
+                    self.waiting = Some(waiting);
+                    return Poll::Pending;
+                }
+                WaitingReceiverInner::Fulfilled(_) => {
+                    let state = std::mem::replace(&mut inner.state, WaitingReceiverInner::Active);
+                    drop(inner);
+                    match state {
+                        WaitingReceiverInner::Fulfilled(WakeReason::MessageToOneActor(m)) => {
+                            // Delivered straight from a sender without ever sitting in a queue, so
+                            // there is no permit to release here; the sender already released it.
+                            return Poll::Ready(Some(m.val));
+                        }
+                        WaitingReceiverInner::Fulfilled(WakeReason::Shutdown) => {
+                            return Poll::Ready(None)
+                        }
+                        WaitingReceiverInner::Active => unreachable!(),
+                    }
+                }
+            }
+        }
+
+        let mut chan = self.inner.chan.lock().unwrap();
+
+        if let Some(m) = chan.priority_queue.pop() {
+            drop(chan);
+            self.inner.release_send_permit();
+            return Poll::Ready(Some(m.val));
+        }
+
+        if let Some(val) = chan.ordered_queue.pop_front() {
+            drop(chan);
+            self.inner.release_send_permit();
+            return Poll::Ready(Some(val));
+        }
+
+        if self.inner.sender_count.load(Ordering::SeqCst) == 0 {
+            return Poll::Ready(None);
+        }
+
+        let waiting = WaitingReceiver::new();
+        waiting.lock().waker = Some(cx.waker().clone());
+        chan.waiting_receivers.push_back(Arc::downgrade(&waiting));
+        self.waiting = Some(waiting);
+        Poll::Pending
+    }
+}
+
+impl<A> Drop for Receiver<A> {
+    fn drop(&mut self) {
+        if self.inner.receiver_count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // We were the last receiver; nothing will ever take another message off this
+            // mailbox, so tell every parked sender (and the channel's cancellation tree) that
+            // the channel is done.
+            self.inner.shutdown();
+
+            // Parked senders wait on their own wakers rather than on `on_shutdown`, so wake each
+            // one directly: it re-polls, finds the channel disconnected and resolves with an error.
+            let waiting_senders = match self.inner.chan.lock() {
+                Ok(mut chan) => std::mem::take(&mut chan.waiting_senders),
+                Err(_) => return, // Poisoned, ignore
+            };
+            for tx in waiting_senders.into_iter().flat_map(|w| w.upgrade()) {
+                let waker = tx.lock().take_waker();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }
+        }
+    }
+}
+
+pub(crate) struct WaitingReceiver<A> {
+    waker: Option<Waker>,
+    state: WaitingReceiverInner<A>,
+}
+
+enum WaitingReceiverInner<A> {
+    Active,
+    Fulfilled(WakeReason<A>),
+}
+
+impl<A> WaitingReceiver<A> {
+    pub(crate) fn new() -> Arc<Spinlock<Self>> {
+        Arc::new(Spinlock::new(WaitingReceiver {
+            waker: None,
+            state: WaitingReceiverInner::Active,
+        }))
+    }
+
+    /// Hands `reason` to this waiter, waking it. Returns the reason back if this waiter was
+    /// already fulfilled.
+    pub(crate) fn fulfill(&mut self, reason: WakeReason<A>) -> Result<(), WakeReason<A>> {
+        if !matches!(self.state, WaitingReceiverInner::Active) {
+            return Err(reason);
+        }
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+        self.state = WaitingReceiverInner::Fulfilled(reason);
+        Ok(())
+    }
+}
+
+/// Future returned by [`Receiver::recv`].
+#[must_use = "Futures do nothing unless polled"]
+pub(crate) struct ReceiveFuture<'a, A> {
+    receiver: &'a mut Receiver<A>,
+}
+
+impl<'a, A> Future for ReceiveFuture<'a, A> {
+    type Output = Option<Box<dyn MessageEnvelope<Actor = A>>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        this.receiver.poll_recv(cx)
+    }
+}
@@ -1,23 +1,83 @@
+use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
 use std::mem;
 use std::pin::Pin;
-use std::sync::{atomic, Arc};
+use std::sync::{atomic, Arc, Weak};
 use std::task::{Context, Poll, Waker};
 
 use event_listener::EventListener;
 use futures_core::FusedFuture;
+use futures_sink::Sink;
 use futures_util::FutureExt;
 
 use super::*;
-use crate::envelope::ShutdownAll;
+use crate::envelope::{NonReturningEnvelope, ShutdownAll};
 use crate::inbox::tx::private::RefCounterInner;
 use crate::send_future::private::SetPriority;
-use crate::{Actor, Error};
+use crate::{Actor, Error, Handler};
+
+/// A lock-free permit counter mirroring tokio's mpsc `Semaphore`: it tracks the bounded channel's
+/// capacity independently of the message queues, so the common send path can acquire a permit
+/// with a single fetch-update and only reach for `Chan::chan`'s mutex once it actually has a
+/// queue to push onto. A permit taken for a message that ends up sitting in `ordered_queue` or
+/// `priority_queue` is released by the receive side once that message is actually taken off the
+/// queue; one released directly here (without ever reaching a queue) was never really spent.
+pub(super) struct Semaphore {
+    capacity: Option<usize>,
+    permits: atomic::AtomicUsize,
+}
+
+impl Semaphore {
+    pub(super) fn new(capacity: Option<usize>) -> Self {
+        Semaphore {
+            capacity,
+            permits: atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Acquires a permit without blocking, returning whether one was available.
+    fn try_acquire(&self) -> bool {
+        let capacity = match self.capacity {
+            Some(capacity) => capacity,
+            None => return true,
+        };
+
+        let mut current = self.permits.load(atomic::Ordering::Acquire);
+        loop {
+            if current >= capacity {
+                return false;
+            }
+
+            match self.permits.compare_exchange_weak(
+                current,
+                current + 1,
+                atomic::Ordering::AcqRel,
+                atomic::Ordering::Acquire,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Releases a permit, making room for the next sender waiting on capacity. Called by the send
+    /// side when a message is delivered directly to a parked receiver without ever reaching a
+    /// queue, or when a reservation is dropped unused or spent on a broadcast, and by the receive
+    /// side when a queued message is popped off `ordered_queue`/`priority_queue`.
+    pub(crate) fn release(&self) {
+        if self.capacity.is_some() {
+            self.permits.fetch_sub(1, atomic::Ordering::AcqRel);
+        }
+    }
+}
 
 pub struct Sender<A, Rc: TxRefCounter> {
     pub(super) inner: Arc<Chan<A>>,
     pub(super) rc: Rc,
+    /// The `Sink` impl's capacity reservation, tracked across `poll_ready`/`start_send` calls —
+    /// see [`SinkReservation`].
+    sink_reservation: SinkReservation<A>,
 }
 
 impl<A> Sender<A, TxStrong> {
@@ -25,49 +85,64 @@ impl<A> Sender<A, TxStrong> {
         let rc = TxStrong(());
         rc.increment(&inner);
 
-        Sender { inner, rc }
+        Sender {
+            inner,
+            rc,
+            sink_reservation: SinkReservation::None,
+        }
     }
 }
 
 impl<Rc: TxRefCounter, A> Sender<A, Rc> {
-    fn try_send(&self, message: SentMessage<A>) -> Result<(), TrySendFail<A>> {
-        let mut inner = self.inner.chan.lock().unwrap();
-
+    /// The internal fast path behind [`SendFuture`]: it already has a boxed [`SentMessage`] in
+    /// hand, so it can go straight to the queues.
+    fn try_send_envelope(&self, message: SentMessage<A>) -> Result<(), TrySendFail<A>> {
         if !self.is_connected() {
             return Err(TrySendFail::Disconnected);
         }
 
         match message {
-            SentMessage::ToAllActors(m) if !self.inner.is_full(inner.broadcast_tail) => {
-                inner.send_broadcast(MessageToAllActors(m));
-                Ok(())
-            }
             SentMessage::ToAllActors(m) => {
-                // on_shutdown is only notified with inner locked, and it's locked here, so no race
+                // Broadcasts aren't gated by the semaphore (they don't sit in `ordered_queue` /
+                // `priority_queue`), so this still has to take the lock up front.
+                let mut inner = self.inner.chan.lock().unwrap();
+
+                if !self.inner.is_full(inner.broadcast_tail) {
+                    inner.send_broadcast(MessageToAllActors(m));
+                    return Ok(());
+                }
+
                 let waiting = WaitingSender::new(SentMessage::ToAllActors(m));
+                prune_waiting_senders(&mut inner.waiting_senders);
                 inner.waiting_senders.push_back(Arc::downgrade(&waiting));
                 Err(TrySendFail::Full(waiting))
             }
-            msg => {
-                let res = inner.try_fulfill_receiver(msg.into());
-                match res {
-                    Ok(()) => Ok(()),
-                    Err(WakeReason::MessageToOneActor(m))
-                        if m.priority == 0 && !self.inner.is_full(inner.ordered_queue.len()) =>
-                    {
-                        inner.ordered_queue.push_back(m.val);
+            SentMessage::ToOneActor(m) => {
+                if !self.inner.semaphore.try_acquire() {
+                    let mut inner = self.inner.chan.lock().unwrap();
+                    let waiting = WaitingSender::new(SentMessage::ToOneActor(m));
+                    prune_waiting_senders(&mut inner.waiting_senders);
+                    inner.waiting_senders.push_back(Arc::downgrade(&waiting));
+                    return Err(TrySendFail::Full(waiting));
+                }
+
+                let mut inner = self.inner.chan.lock().unwrap();
+                match inner.try_fulfill_receiver(WakeReason::MessageToOneActor(m)) {
+                    Ok(()) => {
+                        // Delivered straight to a parked receiver without ever touching a queue,
+                        // so the permit we took for it was never actually spent. `inner` is still
+                        // held here, so release via the already-locked variant rather than
+                        // `Chan::release_send_permit`, which would re-lock `chan` and deadlock.
+                        self.inner.release_send_permit_locked(&inner);
                         Ok(())
                     }
-                    Err(WakeReason::MessageToOneActor(m))
-                        if m.priority != 0 && !self.inner.is_full(inner.priority_queue.len()) =>
-                    {
-                        inner.priority_queue.push(m);
+                    Err(WakeReason::MessageToOneActor(m)) if m.priority == 0 => {
+                        inner.ordered_queue.push_back(m.val);
                         Ok(())
                     }
                     Err(WakeReason::MessageToOneActor(m)) => {
-                        let waiting = WaitingSender::new(m.into());
-                        inner.waiting_senders.push_back(Arc::downgrade(&waiting));
-                        Err(TrySendFail::Full(waiting))
+                        inner.priority_queue.push(m);
+                        Ok(())
                     }
                     _ => unreachable!(),
                 }
@@ -86,14 +161,112 @@ impl<Rc: TxRefCounter, A> Sender<A, Rc> {
             .send_broadcast(MessageToAllActors(Arc::new(ShutdownAll::new())));
     }
 
+    /// Reserves a single slot of capacity without blocking, or returns an error if there is
+    /// currently none free. Sending through the returned [`Permit`] is then infallible and never
+    /// blocks, since the capacity was already secured here.
+    pub fn try_reserve(&self) -> Result<Permit<'_, A, Rc>, TryReserveError> {
+        if !self.is_connected() {
+            return Err(TryReserveError::Disconnected);
+        }
+
+        if !self.inner.semaphore.try_acquire() {
+            return Err(TryReserveError::Full);
+        }
+
+        Ok(Permit {
+            sender: self,
+            consumed: false,
+        })
+    }
+
+    /// Waits until a slot of capacity becomes available, reserving it. See [`Sender::try_reserve`]
+    /// for the non-blocking variant.
+    pub fn reserve(&self) -> ReserveFuture<'_, A, Rc> {
+        ReserveFuture {
+            sender: self,
+            waiting: None,
+        }
+    }
+
+    fn fulfill_reservation(&self, message: SentMessage<A>) {
+        let mut inner = self.inner.chan.lock().unwrap();
+
+        match message {
+            SentMessage::ToAllActors(m) => {
+                // Broadcast capacity isn't tracked by the semaphore, so the permit this
+                // reservation took was never actually spent. `inner` is still held here, so
+                // release via the already-locked variant to avoid re-locking `chan`.
+                self.inner.release_send_permit_locked(&inner);
+                inner.send_broadcast(MessageToAllActors(m));
+            }
+            SentMessage::ToOneActor(m) => {
+                match inner.try_fulfill_receiver(WakeReason::MessageToOneActor(m)) {
+                    Ok(()) => self.inner.release_send_permit_locked(&inner),
+                    Err(WakeReason::MessageToOneActor(m)) if m.priority == 0 => {
+                        inner.ordered_queue.push_back(m.val)
+                    }
+                    Err(WakeReason::MessageToOneActor(m)) => inner.priority_queue.push(m),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Releases a dropped-but-unused reservation's permit, waking a parked sender to race for it.
+    fn release_reservation(&self) {
+        self.inner.release_send_permit();
+    }
+
     pub fn send(&self, message: SentMessage<A>) -> SendFuture<A, Rc> {
         SendFuture::new(message, self.clone())
     }
 
+    /// Attempts to send `message` without waiting for capacity, returning it back via
+    /// [`TrySendError`] if it could not be delivered. This is the non-async counterpart to
+    /// [`Sender::send`], useful outside of async contexts and for drop-under-load policies.
+    pub fn try_send<M>(&self, message: M) -> Result<(), TrySendError<M>>
+    where
+        A: Handler<M>,
+        M: Send + 'static,
+    {
+        if !self.is_connected() {
+            return Err(TrySendError::Disconnected(message));
+        }
+
+        if !self.inner.semaphore.try_acquire() {
+            return Err(TrySendError::Full(message));
+        }
+
+        let envelope = MessageToOneActor {
+            val: Box::new(NonReturningEnvelope::new(message)),
+            priority: 0,
+        };
+
+        let mut inner = self.inner.chan.lock().unwrap();
+        match inner.try_fulfill_receiver(WakeReason::MessageToOneActor(envelope)) {
+            Ok(()) => {
+                // `inner` is still held here, so release via the already-locked variant rather
+                // than `Chan::release_send_permit`, which would re-lock `chan` and deadlock.
+                self.inner.release_send_permit_locked(&inner);
+                Ok(())
+            }
+            Err(WakeReason::MessageToOneActor(m)) if m.priority == 0 => {
+                inner.ordered_queue.push_back(m.val);
+                Ok(())
+            }
+            Err(WakeReason::MessageToOneActor(m)) => {
+                inner.priority_queue.push(m);
+                Ok(())
+            }
+            _ => unreachable!(),
+        }
+    }
+
     pub fn downgrade(&self) -> Sender<A, TxWeak> {
         Sender {
             inner: self.inner.clone(),
             rc: TxWeak(()),
+            sink_reservation: SinkReservation::None,
         }
     }
 
@@ -109,6 +282,7 @@ impl<Rc: TxRefCounter, A> Sender<A, Rc> {
         Sender {
             inner: self.inner.clone(),
             rc: self.rc.increment(&self.inner).into_either(),
+            sink_reservation: SinkReservation::None,
         }
     }
 
@@ -143,6 +317,20 @@ impl<Rc: TxRefCounter, A> Sender<A, Rc> {
             None
         }
     }
+
+    /// The root [`CancellationToken`] tied to this channel's shutdown, shared by every `Sender`
+    /// cloned from the same channel. Cancelling it is wired up alongside `on_shutdown` wherever
+    /// that event is notified, so the two fire together.
+    fn shutdown_cancellation_token(&self) -> crate::cancellation_token::CancellationToken {
+        self.inner.shutdown_token.clone()
+    }
+
+    /// Derives a [`CancellationToken`](crate::cancellation_token::CancellationToken) scoped to
+    /// this channel: cancelling the root (e.g. via a full shutdown) cancels it too, but it can
+    /// also be cancelled independently to abort just the work that holds it.
+    pub fn cancellation_token(&self) -> crate::cancellation_token::CancellationToken {
+        self.shutdown_cancellation_token().child_token()
+    }
 }
 
 impl<A, Rc: TxRefCounter> Clone for Sender<A, Rc> {
@@ -150,6 +338,7 @@ impl<A, Rc: TxRefCounter> Clone for Sender<A, Rc> {
         Sender {
             inner: self.inner.clone(),
             rc: self.rc.increment(&self.inner),
+            sink_reservation: SinkReservation::None,
         }
     }
 }
@@ -225,36 +414,89 @@ impl<A, Rc: TxRefCounter> Future for SendFuture<A, Rc> {
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
         match mem::replace(&mut self.inner, SendFutureInner::Complete) {
-            SendFutureInner::New(msg) => match self.tx.try_send(msg) {
+            SendFutureInner::New(msg) => match self.tx.try_send_envelope(msg) {
                 Ok(()) => Poll::Ready(Ok(())),
                 Err(TrySendFail::Disconnected) => Poll::Ready(Err(Error::Disconnected)),
                 Err(TrySendFail::Full(waiting)) => {
                     // Start waiting. The waiting sender should be immediately polled, in case a
-                    // receive operation happened between `try_send` and here, in which case the
-                    // WaitingSender would be fulfilled, but not properly woken.
+                    // permit was released between `try_send_envelope` and here: the waiter had no
+                    // waker yet, so that release could not wake it.
                     self.inner = SendFutureInner::WaitingToSend(waiting);
                     self.poll_unpin(cx)
                 }
             },
             SendFutureInner::WaitingToSend(waiting) => {
-                {
+                // Being polled again means either we were just parked (and are re-checking for a
+                // race between `try_send_envelope` returning Full and here), or a permit freed up
+                // and we were woken to race for it. Either way, re-attempt the send rather than
+                // trusting stale state, since nothing transitions a waiter out of `New` on its own.
+                let msg = {
                     let mut inner = waiting.lock();
 
-                    match inner.message {
-                        WaitingSenderInner::New(_) => inner.waker = Some(cx.waker().clone()), // The message has not yet been taken
-                        WaitingSenderInner::Delivered => return Poll::Ready(Ok(())),
-                        WaitingSenderInner::Closed => return Poll::Ready(Err(Error::Disconnected)),
+                    if matches!(inner.message, WaitingSenderInner::Delivered) {
+                        drop(inner);
+                        return Poll::Ready(Ok(()));
+                    }
+                    if matches!(
+                        inner.message,
+                        WaitingSenderInner::Closed | WaitingSenderInner::Cancelled
+                    ) {
+                        drop(inner);
+                        return Poll::Ready(Err(Error::Disconnected));
+                    }
+                    if matches!(inner.message, WaitingSenderInner::Reserved) {
+                        unreachable!("a SendFuture's waiter always holds a message");
                     }
-                }
 
-                self.inner = SendFutureInner::WaitingToSend(waiting);
-                Poll::Pending
+                    inner.take_for_retry().expect("checked New above")
+                };
+
+                match self.tx.try_send_envelope(msg) {
+                    Ok(()) => Poll::Ready(Ok(())),
+                    Err(TrySendFail::Disconnected) => Poll::Ready(Err(Error::Disconnected)),
+                    Err(TrySendFail::Full(waiting)) => {
+                        waiting.lock().waker = Some(cx.waker().clone());
+                        self.inner = SendFutureInner::WaitingToSend(waiting);
+                        Poll::Pending
+                    }
+                }
             }
             SendFutureInner::Complete => Poll::Pending,
         }
     }
 }
 
+impl<A, Rc: TxRefCounter> Drop for SendFuture<A, Rc> {
+    fn drop(&mut self) {
+        // If we were parked waiting to send, mark our own waiter cancelled in O(1) so nothing
+        // still holding a `Weak` to it mistakes it for live. The entry itself isn't removed from
+        // `waiting_senders` here — see `prune_waiting_senders` for why that's a deliberate,
+        // separate step rather than an intrusive self-unlink.
+        if let SendFutureInner::WaitingToSend(waiting) = &self.inner {
+            waiting.lock().cancel();
+        }
+    }
+}
+
+/// Drops any waiters in `waiting_senders` that are dead (their `SendFuture` was dropped) or
+/// cancelled, so the queue tracks live blocked senders rather than every sender that ever
+/// blocked. Called on every push, so the only dead entries the queue holds are those of senders
+/// that unparked or were dropped since the last push; they stay until the next push.
+///
+/// This is a `VecDeque` of `Weak` handles pruned by `retain` rather than an intrusive
+/// doubly-linked list with O(1) self-unlink: a self-unlinking node needs either `unsafe` pointer
+/// surgery or a generational arena, and right after each prune this queue holds only the senders
+/// actively blocked on capacity — bounded by whatever concurrency the caller already pays for
+/// elsewhere. An amortized-O(n) `retain` over that bound is cheap enough not to justify
+/// the extra unsafe surface in a crate that otherwise has none; reconsider if profiling ever
+/// shows this queue getting long enough to matter.
+fn prune_waiting_senders<A>(waiting_senders: &mut VecDeque<Weak<Spinlock<WaitingSender<A>>>>) {
+    waiting_senders.retain(|weak| match weak.upgrade() {
+        Some(waiting) => !matches!(waiting.lock().message, WaitingSenderInner::Cancelled),
+        None => false,
+    });
+}
+
 pub struct WaitingSender<A> {
     waker: Option<Waker>,
     message: WaitingSenderInner<A>,
@@ -262,8 +504,13 @@ pub struct WaitingSender<A> {
 
 enum WaitingSenderInner<A> {
     New(SentMessage<A>),
+    /// A slot of capacity reserved via [`Sender::reserve`], with no message attached yet.
+    Reserved,
     Delivered,
     Closed,
+    /// The waiting future was dropped before it got a chance to send; the channel can skip over
+    /// (and eventually prune) this entry without upgrading a dangling `Weak` first.
+    Cancelled,
 }
 
 impl<A> WaitingSender<A> {
@@ -275,27 +522,173 @@ impl<A> WaitingSender<A> {
         Arc::new(Spinlock::new(sender))
     }
 
-    pub fn peek(&self) -> &SentMessage<A> {
-        match &self.message {
-            WaitingSenderInner::New(msg) => msg,
-            _ => panic!("WaitingSender should have message"),
+    /// Creates a waiter for a bare capacity reservation, with no message to deliver.
+    pub fn new_reservation() -> Arc<Spinlock<Self>> {
+        let sender = WaitingSender {
+            waker: None,
+            message: WaitingSenderInner::Reserved,
+        };
+        Arc::new(Spinlock::new(sender))
+    }
+
+    /// Takes this waiter's message back so its [`SendFuture`] can retry against a (possibly now
+    /// free) permit, marking the waiter cancelled so nothing else tries to act on it afterwards.
+    /// Returns `None` if there was no message to reclaim (e.g. it was already delivered).
+    fn take_for_retry(&mut self) -> Option<SentMessage<A>> {
+        match mem::replace(&mut self.message, WaitingSenderInner::Cancelled) {
+            WaitingSenderInner::New(msg) => Some(msg),
+            other => {
+                self.message = other;
+                None
+            }
         }
     }
 
-    pub fn fulfill(&mut self, is_delivered: bool) -> SentMessage<A> {
-        if let Some(waker) = self.waker.take() {
-            waker.wake();
+    /// Takes this waiter's waker, if any, so the caller can wake it outside of `tx`'s own types.
+    pub(crate) fn take_waker(&mut self) -> Option<Waker> {
+        self.waker.take()
+    }
+
+    /// Marks this waiter as abandoned by its owning future, dropping whatever message it held.
+    fn cancel(&mut self) {
+        if matches!(
+            self.message,
+            WaitingSenderInner::New(_) | WaitingSenderInner::Reserved
+        ) {
+            self.message = WaitingSenderInner::Cancelled;
         }
+    }
+}
 
-        let new = if is_delivered {
-            WaitingSenderInner::Delivered
-        } else {
-            WaitingSenderInner::Closed
-        };
+/// An error returned by [`Sender::try_reserve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The bounded channel has no free capacity right now.
+    Full,
+    /// There are no receivers left to deliver to.
+    Disconnected,
+}
+
+/// An error returned by [`Sender::try_send`], handing the undelivered message back to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<M> {
+    /// The bounded channel has no free capacity right now; the message was not sent.
+    Full(M),
+    /// There are no receivers left to deliver to; the message was not sent.
+    Disconnected(M),
+}
+
+/// A reserved slot of channel capacity, obtained from [`Sender::reserve`] or
+/// [`Sender::try_reserve`]. Sending through a permit is infallible and never blocks, since the
+/// capacity was already secured when the permit was created. Dropping a permit without sending
+/// releases the reservation back to the channel.
+pub struct Permit<'a, A, Rc: TxRefCounter> {
+    sender: &'a Sender<A, Rc>,
+    consumed: bool,
+}
+
+impl<'a, A, Rc: TxRefCounter> Permit<'a, A, Rc> {
+    /// Consumes the permit, sending `message` into the slot it reserved.
+    pub fn send(mut self, message: SentMessage<A>) {
+        self.consumed = true;
+        self.sender.fulfill_reservation(message);
+    }
+
+    /// Upgrades this permit into an [`OwnedPermit`] that owns a cloned [`Sender`], so it can be
+    /// moved into a `'static` task.
+    pub fn into_owned(mut self) -> OwnedPermit<A, Rc> {
+        self.consumed = true;
+        OwnedPermit {
+            sender: self.sender.clone(),
+            consumed: false,
+        }
+    }
+}
 
-        match mem::replace(&mut self.message, new) {
-            WaitingSenderInner::New(msg) => msg,
-            _ => panic!("WaitingSender should have message"),
+impl<'a, A, Rc: TxRefCounter> Drop for Permit<'a, A, Rc> {
+    fn drop(&mut self) {
+        if !self.consumed {
+            self.sender.release_reservation();
+        }
+    }
+}
+
+/// Like [`Permit`], but owns its [`Sender`] rather than borrowing it, so it can be held across
+/// `'static` boundaries (e.g. moved into a spawned task).
+pub struct OwnedPermit<A, Rc: TxRefCounter> {
+    sender: Sender<A, Rc>,
+    consumed: bool,
+}
+
+impl<A, Rc: TxRefCounter> OwnedPermit<A, Rc> {
+    /// Consumes the permit, sending `message` into the slot it reserved, and hands back the
+    /// underlying [`Sender`] so it can be reused for another reservation.
+    pub fn send(mut self, message: SentMessage<A>) -> Sender<A, Rc> {
+        self.consumed = true;
+        self.sender.fulfill_reservation(message);
+        self.sender.clone()
+    }
+}
+
+impl<A, Rc: TxRefCounter> Drop for OwnedPermit<A, Rc> {
+    fn drop(&mut self) {
+        if !self.consumed {
+            self.sender.release_reservation();
+        }
+    }
+}
+
+/// Future returned by [`Sender::reserve`].
+#[must_use = "Futures do nothing unless polled"]
+pub struct ReserveFuture<'a, A, Rc: TxRefCounter> {
+    sender: &'a Sender<A, Rc>,
+    waiting: Option<Arc<Spinlock<WaitingSender<A>>>>,
+}
+
+impl<'a, A, Rc: TxRefCounter> Future for ReserveFuture<'a, A, Rc> {
+    type Output = Result<Permit<'a, A, Rc>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(waiting) = self.waiting.take() {
+            let mut inner = waiting.lock();
+            match inner.message {
+                WaitingSenderInner::Reserved => {
+                    // Being woken here only means a permit *might* be free again, not that this
+                    // specific waiter was fulfilled (nothing ever transitions a bare reservation
+                    // waiter to `Delivered` on its own) — so give it up and race for a fresh one
+                    // via `try_reserve` below, exactly like the very first poll would.
+                    inner.cancel();
+                    drop(inner);
+                }
+                WaitingSenderInner::Delivered => {
+                    return Poll::Ready(Ok(Permit {
+                        sender: self.sender,
+                        consumed: false,
+                    }));
+                }
+                WaitingSenderInner::Closed | WaitingSenderInner::Cancelled => {
+                    return Poll::Ready(Err(Error::Disconnected))
+                }
+                WaitingSenderInner::New(_) => {
+                    unreachable!("a reservation waiter never holds a message")
+                }
+            }
+        }
+
+        match self.sender.try_reserve() {
+            Ok(permit) => Poll::Ready(Ok(permit)),
+            Err(TryReserveError::Disconnected) => Poll::Ready(Err(Error::Disconnected)),
+            Err(TryReserveError::Full) => {
+                let waiting = WaitingSender::new_reservation();
+                waiting.lock().waker = Some(cx.waker().clone());
+
+                let mut inner = self.sender.inner.chan.lock().unwrap();
+                prune_waiting_senders(&mut inner.waiting_senders);
+                inner.waiting_senders.push_back(Arc::downgrade(&waiting));
+
+                self.waiting = Some(waiting);
+                Poll::Pending
+            }
         }
     }
 }
@@ -306,6 +699,120 @@ impl<A, Rc: TxRefCounter> FusedFuture for SendFuture<A, Rc> {
     }
 }
 
+/// The `Sink` impl's capacity state, carried on the `Sender` itself between `poll_ready` and
+/// `start_send` calls so the two can share a single permit instead of each re-checking capacity
+/// independently. Mirrors the reserve-then-send split [`Sender::try_reserve`]/[`Permit::send`]
+/// already give non-`Sink` callers.
+enum SinkReservation<A> {
+    /// No permit currently held or being waited on.
+    None,
+    /// `poll_ready` acquired a permit that `start_send` hasn't spent yet.
+    Reserved,
+    /// `poll_ready` is parked waiting for a permit to free up.
+    Parked(Arc<Spinlock<WaitingSender<A>>>),
+}
+
+impl<A, Rc, M> Sink<M> for Sender<A, Rc>
+where
+    Rc: TxRefCounter,
+    A: Handler<M>,
+    M: Send + 'static,
+{
+    type Error = Error;
+
+    /// Maps onto capacity the same way [`Sender::reserve`] does: returns `Ready(Ok)` only once a
+    /// permit is actually in hand, parking (and registering `cx`'s waker) when the bounded queue
+    /// is full rather than buffering the eventual message unboundedly.
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+
+        loop {
+            match mem::replace(&mut this.sink_reservation, SinkReservation::None) {
+                SinkReservation::Reserved => {
+                    this.sink_reservation = SinkReservation::Reserved;
+                    return Poll::Ready(Ok(()));
+                }
+                SinkReservation::Parked(waiting) => {
+                    let mut inner = waiting.lock();
+                    match inner.message {
+                        WaitingSenderInner::Reserved => {
+                            // Being woken only means a permit *might* be free, not that this
+                            // specific waiter was fulfilled — nothing ever transitions a bare
+                            // reservation waiter out of `Reserved` on its own (see
+                            // `ReserveFuture`). Give it up and race for a fresh one below;
+                            // `sink_reservation` is already `None` from the `mem::replace` above.
+                            inner.cancel();
+                            drop(inner);
+                        }
+                        WaitingSenderInner::Closed | WaitingSenderInner::Cancelled => {
+                            drop(inner);
+                            return Poll::Ready(Err(Error::Disconnected));
+                        }
+                        WaitingSenderInner::Delivered => {
+                            unreachable!("a reservation waiter is never delivered")
+                        }
+                        WaitingSenderInner::New(_) => {
+                            unreachable!("a reservation waiter never holds a message")
+                        }
+                    }
+                }
+                SinkReservation::None => {
+                    if !this.is_connected() {
+                        return Poll::Ready(Err(Error::Disconnected));
+                    }
+
+                    if this.inner.semaphore.try_acquire() {
+                        this.sink_reservation = SinkReservation::Reserved;
+                        return Poll::Ready(Ok(()));
+                    }
+
+                    let waiting = WaitingSender::new_reservation();
+                    waiting.lock().waker = Some(cx.waker().clone());
+
+                    let mut inner = this.inner.chan.lock().unwrap();
+                    prune_waiting_senders(&mut inner.waiting_senders);
+                    inner.waiting_senders.push_back(Arc::downgrade(&waiting));
+                    drop(inner);
+
+                    this.sink_reservation = SinkReservation::Parked(waiting);
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    /// Spends the permit `poll_ready` reserved. Per the `Sink` contract this is only ever called
+    /// right after `poll_ready` returned `Ready(Ok)`, so the permit is always there to spend —
+    /// this never has to park a waiting sender the way [`SendFuture`] does, or report `Full` the
+    /// way [`Sender::try_send`] does.
+    fn start_send(self: Pin<&mut Self>, item: M) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+
+        assert!(
+            matches!(this.sink_reservation, SinkReservation::Reserved),
+            "start_send called without a successful poll_ready"
+        );
+        this.sink_reservation = SinkReservation::None;
+
+        let envelope = MessageToOneActor {
+            val: Box::new(NonReturningEnvelope::new(item)),
+            priority: 0,
+        };
+        this.fulfill_reservation(SentMessage::ToOneActor(envelope));
+        Ok(())
+    }
+
+    /// Always ready: `start_send` fully delivers (or queues) its message before returning, so
+    /// there is nothing left to flush.
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.poll_flush(cx)
+    }
+}
+
 /// This trait represents the strength of an address's reference counting. It is an internal trait.
 /// There are two implementations of this trait: [`Weak`](TxWeak) and [`Strong`](TxStrong). These
 /// can be provided as the second type argument to [`Address`](crate::Address) in order to change how the address